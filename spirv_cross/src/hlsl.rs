@@ -1,12 +1,28 @@
 use crate::bindings as br;
 use crate::{compiler, spirv, ErrorCode};
-use std::ffi::CString;
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::{CStr, CString};
+use std::fs;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::path::PathBuf;
 use std::ptr;
 
 pub use crate::bindings::root::ScHlslRootConstant as RootConstant;
 
-#[derive(Debug, Copy, Clone)]
+/// Per-target state carried alongside the underlying SPIRV-Cross compiler
+/// instance, populated as the caller configures the AST.
+#[derive(Debug, Clone, Default)]
+pub struct HlslTargetData {
+    last_options: Option<CompilerOptions>,
+    spirv_words: Vec<u32>,
+    resource_bindings: Vec<HlslResourceBinding>,
+    vertex_attribute_remaps: Vec<HlslVertexAttributeRemap>,
+    root_constant_layout: Option<Vec<RootConstant>>,
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
 pub struct HlslResourceBindingSpaceRegister {
     pub register_space: u32,
     pub register_binding: u32,
@@ -29,12 +45,91 @@ pub struct HlslVertexAttributeRemap {
     pub semantic: String
 }
 
+/// The HLSL resource class a reflected resource maps onto.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ResourceKind {
+    /// A uniform/constant buffer, bound as a `cbuffer`.
+    Cbv,
+    /// A read-only resource, bound as a `Texture*`/`Buffer`/`StructuredBuffer`.
+    Srv,
+    /// A read-write resource, bound as a `RWTexture*`/`RWStructuredBuffer`.
+    Uav,
+    /// A `SamplerState`.
+    Sampler,
+}
+
+/// A single shader resource reflected from the module, along with the
+/// descriptor set/binding decorations SPIRV-Cross tracked for it.
+#[derive(Debug, Clone)]
+pub struct Resource {
+    /// The SPIR-V id of the resource variable.
+    pub id: u32,
+    /// The name of the resource as it will appear in the generated HLSL.
+    pub name: String,
+    pub desc_set: u32,
+    pub binding: u32,
+    /// Whether the resource is written to, i.e. not decorated `NonWritable`.
+    pub writable: bool,
+    pub kind: ResourceKind,
+}
+
+/// All shader resources used by the module, grouped by SPIR-V resource
+/// category, as returned by [`spirv::Ast::get_shader_resources`].
+#[derive(Debug, Clone, Default)]
+pub struct ShaderResources {
+    pub uniform_buffers: Vec<Resource>,
+    pub storage_buffers: Vec<Resource>,
+    pub sampled_images: Vec<Resource>,
+    pub storage_images: Vec<Resource>,
+    pub samplers: Vec<Resource>,
+}
+
+/// An entry point reflected from the module, as returned by
+/// [`spirv::Ast::get_entry_points`].
+#[derive(Debug, Clone)]
+pub struct EntryPoint {
+    pub name: String,
+    pub execution_model: spirv::ExecutionModel,
+}
+
+/// The byte offset, size, and array stride of an active buffer member, as
+/// SPIRV-Cross laid it out for the target std140/std430-style rules. `size`
+/// is the member's own size (e.g. 12 bytes for a `vec3`); `stride` is the
+/// byte distance between consecutive array elements (16 bytes for a
+/// `vec3[]` under std140/std430), and is `0` for non-array members.
+#[derive(Debug, Copy, Clone)]
+pub struct BufferRange {
+    pub offset: usize,
+    pub size: usize,
+    pub stride: usize,
+}
+
+/// A single active member of a reflected constant buffer, along with its
+/// nested members if it is itself a struct.
+#[derive(Debug, Clone)]
+pub struct BufferRangeMember {
+    pub name: String,
+    pub range: BufferRange,
+    /// Whether a matrix member is laid out row-major, as decorated.
+    pub row_major: bool,
+    pub members: Vec<BufferRangeMember>,
+}
+
+/// The full member layout of a constant buffer resource, as returned by
+/// [`spirv::Ast::get_buffer_layout`].
+#[derive(Debug, Clone)]
+pub struct BufferLayout {
+    /// The total declared size of the buffer's backing struct, in bytes.
+    pub total_size: usize,
+    pub members: Vec<BufferRangeMember>,
+}
+
 /// A HLSL target.
 #[derive(Debug, Clone)]
 pub enum Target {}
 
 impl spirv::Target for Target {
-    type Data = ();
+    type Data = HlslTargetData;
 }
 
 /// A HLSL shader model version.
@@ -108,6 +203,9 @@ pub struct CompilerOptions {
     /// The name and execution model of the entry point to use. If no entry
     /// point is specified, then the first entry point found will be used.
     pub entry_point: Option<(String, spirv::ExecutionModel)>,
+    /// Skip the on-disk HLSL cache for this compile, even if a cache
+    /// directory has been configured with [`spirv::Ast::set_hlsl_cache_dir`].
+    pub bypass_cache: bool,
 }
 
 impl Default for CompilerOptions {
@@ -122,6 +220,7 @@ impl Default for CompilerOptions {
             force_zero_initialized_variables: false,
             flatten_matrix_vertex_input_semantics: false,
             entry_point: None,
+            bypass_cache: false,
         }
     }
 }
@@ -140,7 +239,10 @@ impl spirv::Parse<Target> for spirv::Ast<Target> {
 
             compiler::Compiler {
                 sc_compiler: compiler,
-                target_data: (),
+                target_data: HlslTargetData {
+                    spirv_words: module.words.to_vec(),
+                    ..HlslTargetData::default()
+                },
                 has_been_compiled: false,
             }
         };
@@ -157,6 +259,8 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
 
     /// Set HLSL compiler specific compilation settings.
     fn set_compiler_options(&mut self, options: &CompilerOptions) -> Result<(), ErrorCode> {
+        self.compiler.target_data.last_options = Some(options.clone());
+
         if let Some((name, model)) = &options.entry_point {
             let name_raw = CString::new(name.as_str()).map_err(|_| ErrorCode::Unhandled)?;
             let model = model.as_raw();
@@ -190,8 +294,92 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
     }
 
     /// Generate HLSL shader from the AST.
+    ///
+    /// If a cache directory has been configured via
+    /// [`set_hlsl_cache_dir`](spirv::Ast::set_hlsl_cache_dir) and the current
+    /// options don't request [`CompilerOptions::bypass_cache`], the SPIR-V
+    /// words together with the last options passed to
+    /// [`set_compiler_options`](spirv::Compile::set_compiler_options) and every
+    /// resource binding, vertex attribute remap, and root constant layout call
+    /// made so far are hashed into a single digest and used to look up a previously-emitted
+    /// HLSL string before the FFI compiler is invoked.
     fn compile(&mut self) -> Result<String, ErrorCode> {
-        self.compiler.compile()
+        let bypass_cache = self
+            .compiler
+            .target_data
+            .last_options
+            .as_ref()
+            .map(|options| options.bypass_cache)
+            .unwrap_or(false);
+
+        let cache_dir = match (&self.compiler.target_data.cache_dir, bypass_cache) {
+            (Some(dir), false) => Some(dir.clone()),
+            _ => None,
+        };
+
+        let cache_dir = match cache_dir {
+            Some(dir) => dir,
+            None => return self.compiler.compile(),
+        };
+
+        let cache_path = cache_dir.join(format!("{:016x}.hlsl", self.compiler.target_data.cache_digest()));
+
+        if let Ok(cached) = fs::read_to_string(&cache_path) {
+            return Ok(cached);
+        }
+
+        let hlsl = self.compiler.compile()?;
+        let _ = fs::create_dir_all(&cache_dir);
+
+        // Write to a sibling temp file and rename into place so a reader never
+        // observes a partially-written cache entry.
+        let tmp_path = cache_dir.join(format!("{:016x}.hlsl.tmp.{}", self.compiler.target_data.cache_digest(), std::process::id()));
+        if fs::write(&tmp_path, &hlsl).is_ok() {
+            let _ = fs::rename(&tmp_path, &cache_path);
+        }
+
+        Ok(hlsl)
+    }
+}
+
+impl HlslTargetData {
+    /// Digest the SPIR-V words, the last applied [`CompilerOptions`], and any
+    /// registered resource bindings, vertex attribute remaps, or root
+    /// constant layout into a single cache key.
+    fn cache_digest(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.spirv_words.hash(&mut hasher);
+        format!("{:?}", self.last_options).hash(&mut hasher);
+        format!("{:?}", self.resource_bindings).hash(&mut hasher);
+        format!("{:?}", self.vertex_attribute_remaps).hash(&mut hasher);
+        format!("{:?}", self.root_constant_layout).hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod cache_digest_tests {
+    use super::*;
+
+    #[test]
+    fn cache_digest_distinguishes_root_constant_layout_contents() {
+        let mut a = HlslTargetData::default();
+        let mut b = HlslTargetData::default();
+
+        a.root_constant_layout = Some(vec![RootConstant {
+            start: 0,
+            end: 4,
+            binding: 0,
+            space: 0,
+        }]);
+        b.root_constant_layout = Some(vec![RootConstant {
+            start: 4,
+            end: 8,
+            binding: 0,
+            space: 0,
+        }]);
+
+        assert_ne!(a.cache_digest(), b.cache_digest());
     }
 }
 
@@ -206,9 +394,18 @@ impl spirv::Ast<Target> {
             ));
         }
 
+        self.compiler.target_data.root_constant_layout = Some(layout);
+
         Ok(())
     }
 
+    /// Point the HLSL compile cache at a directory on disk. Once set, calls
+    /// to [`compile`](spirv::Compile::compile) will look up and store emitted
+    /// HLSL there, keyed on the SPIR-V input and the compiler configuration.
+    pub fn set_hlsl_cache_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.compiler.target_data.cache_dir = Some(dir.into());
+    }
+
     ///
     pub fn add_vertex_attribute_remap(&mut self, remap: &HlslVertexAttributeRemap) -> Result<(), ErrorCode> {
         let semantic = CString::new(remap.semantic.as_str()).map_err(|_| ErrorCode::Unhandled)?;
@@ -222,6 +419,8 @@ impl spirv::Ast<Target> {
             check!(br::sc_internal_compiler_hlsl_add_vertex_attribute_remap(self.compiler.sc_compiler, r));
         }
 
+        self.compiler.target_data.vertex_attribute_remaps.push(remap.clone());
+
         Ok(())
     }
 
@@ -231,6 +430,8 @@ impl spirv::Ast<Target> {
             crate::bindings::root::ScHlslResourceBindingSpaceRegister { register_space: space_register.register_space, register_binding: space_register.register_binding }
         }
 
+        let resource_binding_for_cache = resource_binding.clone();
+
         let resource_binding = crate::bindings::root::ScHlslResourceBinding {
             stage: resource_binding.stage.as_raw(),
             desc_set: resource_binding.desc_set,
@@ -248,6 +449,455 @@ impl spirv::Ast<Target> {
             ));
         }
 
+        self.compiler
+            .target_data
+            .resource_bindings
+            .push(resource_binding_for_cache);
+
         Ok(())
     }
+
+    /// Compile straight to a DXIL blob for shader-model 6.0 targets.
+    ///
+    /// This emits HLSL via the usual [`compile`](spirv::Compile::compile) path and
+    /// then drives the DirectX Shader Compiler over that source, using the entry
+    /// point name recorded by [`CompilerOptions::entry_point`] (falling back to
+    /// `"main"`). The DXC target profile (e.g. `"vs_6_0"`, `"ps_6_0"`) is derived
+    /// from that same entry point's `spirv::ExecutionModel`; pass `Some(profile)`
+    /// to override it instead.
+    pub fn compile_to_dxil(&mut self, profile_override: Option<&str>) -> Result<Vec<u8>, ErrorCode> {
+        let options = self.compiler.target_data.last_options.clone().unwrap_or_default();
+
+        if options.shader_model != ShaderModel::V6_0 {
+            return Err(ErrorCode::Unhandled);
+        }
+
+        let entry_point = options
+            .entry_point
+            .as_ref()
+            .map(|(name, _)| name.as_str())
+            .unwrap_or("main");
+
+        let profile = match profile_override {
+            Some(profile) => profile.to_owned(),
+            None => {
+                let model = options
+                    .entry_point
+                    .as_ref()
+                    .map(|(_, model)| *model)
+                    .ok_or(ErrorCode::Unhandled)?;
+                format!("{}_6_0", dxil_profile_prefix(model)?)
+            }
+        };
+
+        let hlsl = <Self as spirv::Compile<Target>>::compile(self)?;
+
+        hassle_rs::compile_hlsl("spirv-cross.hlsl", &hlsl, entry_point, &profile, &[], &[])
+            .map_err(|e| ErrorCode::CompilationError(e.to_string()))
+    }
+
+    /// Enumerate the uniform buffers, storage buffers, sampled images,
+    /// storage images, and samplers used by the module, along with their
+    /// `desc_set`/`binding` decorations and inferred HLSL resource class.
+    ///
+    /// A storage buffer or storage image's `kind` is derived the same way
+    /// the HLSL backend decides it when emitting `compile()`'s output: a
+    /// storage buffer is a SRV (`StructuredBuffer`) unless it's written to or
+    /// [`CompilerOptions::force_storage_buffer_as_uav`] is set, and a storage
+    /// image is a SRV (`Texture*`) only if it's non-writable and
+    /// [`CompilerOptions::nonwritable_uav_texture_as_srv`] is set; otherwise
+    /// both are UAVs.
+    pub fn get_shader_resources(&self) -> Result<ShaderResources, ErrorCode> {
+        let mut raw = br::ScHlslShaderResources::default();
+
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_get_shader_resources(
+                self.compiler.sc_compiler,
+                &mut raw,
+            ));
+
+            let options = self.compiler.target_data.last_options.clone().unwrap_or_default();
+
+            let result = (|| {
+                Ok(ShaderResources {
+                    uniform_buffers: resources_from_raw(raw.uniform_buffers, raw.uniform_buffers_size, |_| {
+                        ResourceKind::Cbv
+                    })?,
+                    storage_buffers: resources_from_raw(raw.storage_buffers, raw.storage_buffers_size, |writable| {
+                        storage_buffer_kind(writable, options.force_storage_buffer_as_uav)
+                    })?,
+                    sampled_images: resources_from_raw(raw.sampled_images, raw.sampled_images_size, |_| {
+                        ResourceKind::Srv
+                    })?,
+                    storage_images: resources_from_raw(raw.storage_images, raw.storage_images_size, |writable| {
+                        storage_image_kind(writable, options.nonwritable_uav_texture_as_srv)
+                    })?,
+                    samplers: resources_from_raw(raw.samplers, raw.samplers_size, |_| ResourceKind::Sampler)?,
+                })
+            })();
+
+            br::sc_internal_compiler_hlsl_free_shader_resources(&mut raw);
+
+            result
+        }
+    }
+
+    /// Build and install a full flat register binding table in one call.
+    ///
+    /// Enumerates every resource via [`get_shader_resources`](Self::get_shader_resources)
+    /// and calls `f` with the resource's `spirv::ExecutionModel`, `desc_set`,
+    /// `binding`, and [`ResourceKind`] to decide where it lands, then installs
+    /// the resulting bindings with [`add_resource_binding`](Self::add_resource_binding).
+    /// The execution model is taken from the entry point configured via
+    /// [`CompilerOptions::entry_point`]; call [`set_compiler_options`](spirv::Compile::set_compiler_options)
+    /// with an explicit entry point first.
+    ///
+    /// The installed `cbv`/`srv`/`uav`/`sampler` slot for each resource is
+    /// chosen from [`Resource::kind`], so this table only matches what
+    /// `compile()` emits as long as `get_shader_resources` derives `kind`
+    /// the same way the HLSL backend does (storage buffer/image writability,
+    /// `force_storage_buffer_as_uav`, `nonwritable_uav_texture_as_srv`).
+    ///
+    /// The simplest flat mapping, `register_space = desc_set` and
+    /// `register_binding = binding`, can be installed with:
+    /// `ast.set_resource_binding_map(|_, desc_set, binding, _| HlslResourceBindingSpaceRegister { register_space: desc_set, register_binding: binding })`.
+    pub fn set_resource_binding_map(
+        &mut self,
+        mut f: impl FnMut(spirv::ExecutionModel, u32, u32, ResourceKind) -> HlslResourceBindingSpaceRegister,
+    ) -> Result<(), ErrorCode> {
+        let stage = self
+            .compiler
+            .target_data
+            .last_options
+            .as_ref()
+            .and_then(|options| options.entry_point.as_ref())
+            .map(|(_, model)| *model)
+            .ok_or(ErrorCode::Unhandled)?;
+
+        let resources = self.get_shader_resources()?;
+
+        let all_resources = resources
+            .uniform_buffers
+            .iter()
+            .chain(resources.storage_buffers.iter())
+            .chain(resources.sampled_images.iter())
+            .chain(resources.storage_images.iter())
+            .chain(resources.samplers.iter());
+
+        for resource in all_resources {
+            let register = f(stage, resource.desc_set, resource.binding, resource.kind);
+            let binding = resource_binding_for_register(stage, resource, register);
+            self.add_resource_binding(&binding)?;
+        }
+
+        Ok(())
+    }
+
+    /// Enumerate every entry point the module carries, paired with its
+    /// `spirv::ExecutionModel`, so front-ends can present choices or
+    /// validate a requested entry point before calling
+    /// [`set_compiler_options`](spirv::Compile::set_compiler_options).
+    pub fn get_entry_points(&self) -> Result<Vec<EntryPoint>, ErrorCode> {
+        let mut raw_entry_points = ptr::null_mut();
+        let mut raw_entry_points_size = 0usize;
+
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_get_entry_points(
+                self.compiler.sc_compiler,
+                &mut raw_entry_points,
+                &mut raw_entry_points_size,
+            ));
+
+            if raw_entry_points.is_null() || raw_entry_points_size == 0 {
+                return Ok(Vec::new());
+            }
+
+            let result = std::slice::from_raw_parts(raw_entry_points, raw_entry_points_size)
+                .iter()
+                .map(|raw| {
+                    let name = if raw.name.is_null() {
+                        String::new()
+                    } else {
+                        CStr::from_ptr(raw.name)
+                            .to_str()
+                            .map_err(|_| ErrorCode::Unhandled)?
+                            .to_owned()
+                    };
+
+                    Ok(EntryPoint {
+                        name,
+                        execution_model: spirv::ExecutionModel::from_raw(raw.execution_model),
+                    })
+                })
+                .collect();
+
+            br::sc_internal_compiler_hlsl_free_entry_points(raw_entry_points, raw_entry_points_size);
+
+            result
+        }
+    }
+
+    /// Reflect the byte offsets, sizes, and array strides of each active
+    /// member of the uniform/constant buffer resource `resource_id`, as
+    /// returned by [`get_shader_resources`](Self::get_shader_resources),
+    /// along with the buffer's total declared struct size.
+    pub fn get_buffer_layout(&self, resource_id: u32) -> Result<BufferLayout, ErrorCode> {
+        let mut raw = br::ScHlslBufferLayout::default();
+
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_get_buffer_layout(
+                self.compiler.sc_compiler,
+                resource_id,
+                &mut raw,
+            ));
+
+            let result = (|| {
+                Ok(BufferLayout {
+                    total_size: raw.total_size,
+                    members: buffer_members_from_raw(raw.members, raw.members_size)?,
+                })
+            })();
+
+            br::sc_internal_compiler_hlsl_free_buffer_layout(&mut raw);
+
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod entry_point_tests {
+    use super::*;
+
+    #[test]
+    fn entry_points_can_be_looked_up_by_name_and_stage() {
+        let entry_points = vec![
+            EntryPoint {
+                name: "vs_main".to_owned(),
+                execution_model: spirv::ExecutionModel::Vertex,
+            },
+            EntryPoint {
+                name: "ps_main".to_owned(),
+                execution_model: spirv::ExecutionModel::Fragment,
+            },
+        ];
+
+        let found = entry_points
+            .iter()
+            .find(|entry_point| entry_point.name == "ps_main")
+            .expect("ps_main should be present");
+
+        assert_eq!(found.execution_model, spirv::ExecutionModel::Fragment);
+    }
+}
+
+unsafe fn buffer_members_from_raw(
+    ptr: *const br::ScHlslBufferRangeMember,
+    len: usize,
+) -> Result<Vec<BufferRangeMember>, ErrorCode> {
+    if ptr.is_null() || len == 0 {
+        return Ok(Vec::new());
+    }
+
+    std::slice::from_raw_parts(ptr, len)
+        .iter()
+        .map(|raw| {
+            let name = if raw.name.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(raw.name)
+                    .to_str()
+                    .map_err(|_| ErrorCode::Unhandled)?
+                    .to_owned()
+            };
+
+            Ok(BufferRangeMember {
+                name,
+                range: BufferRange {
+                    offset: raw.offset,
+                    size: raw.size,
+                    stride: raw.stride,
+                },
+                row_major: raw.row_major,
+                members: buffer_members_from_raw(raw.members, raw.members_size)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod buffer_layout_tests {
+    use super::*;
+
+    #[test]
+    fn buffer_range_stride_is_independent_of_size() {
+        // A `vec3[]` member under std140/std430: 12-byte elements rounded up
+        // to a 16-byte stride between array entries.
+        let range = BufferRange {
+            offset: 0,
+            size: 12,
+            stride: 16,
+        };
+
+        assert_eq!(range.size, 12);
+        assert_eq!(range.stride, 16);
+    }
+}
+
+/// The DXC target profile prefix (`vs`, `ps`, ...) for a shader stage.
+fn dxil_profile_prefix(model: spirv::ExecutionModel) -> Result<&'static str, ErrorCode> {
+    use spirv::ExecutionModel::*;
+    match model {
+        Vertex => Ok("vs"),
+        Fragment => Ok("ps"),
+        GlCompute | Kernel => Ok("cs"),
+        Geometry => Ok("gs"),
+        TessellationControl => Ok("hs"),
+        TessellationEvaluation => Ok("ds"),
+        _ => Err(ErrorCode::Unhandled),
+    }
+}
+
+#[cfg(test)]
+mod dxil_profile_tests {
+    use super::*;
+
+    #[test]
+    fn dxil_profile_prefix_maps_execution_models() {
+        assert_eq!(dxil_profile_prefix(spirv::ExecutionModel::Vertex).unwrap(), "vs");
+        assert_eq!(dxil_profile_prefix(spirv::ExecutionModel::Fragment).unwrap(), "ps");
+        assert_eq!(dxil_profile_prefix(spirv::ExecutionModel::GlCompute).unwrap(), "cs");
+        assert_eq!(dxil_profile_prefix(spirv::ExecutionModel::Kernel).unwrap(), "cs");
+        assert_eq!(dxil_profile_prefix(spirv::ExecutionModel::Geometry).unwrap(), "gs");
+        assert_eq!(dxil_profile_prefix(spirv::ExecutionModel::TessellationControl).unwrap(), "hs");
+        assert_eq!(dxil_profile_prefix(spirv::ExecutionModel::TessellationEvaluation).unwrap(), "ds");
+    }
+}
+
+/// Place `register` into the `cbv`/`srv`/`uav`/`sampler` slot matching
+/// `resource.kind`, leaving the other three slots at their default.
+fn resource_binding_for_register(
+    stage: spirv::ExecutionModel,
+    resource: &Resource,
+    register: HlslResourceBindingSpaceRegister,
+) -> HlslResourceBinding {
+    let mut binding = HlslResourceBinding {
+        stage,
+        desc_set: resource.desc_set,
+        binding: resource.binding,
+        cbv: HlslResourceBindingSpaceRegister::default(),
+        uav: HlslResourceBindingSpaceRegister::default(),
+        srv: HlslResourceBindingSpaceRegister::default(),
+        sampler: HlslResourceBindingSpaceRegister::default(),
+    };
+
+    match resource.kind {
+        ResourceKind::Cbv => binding.cbv = register,
+        ResourceKind::Srv => binding.srv = register,
+        ResourceKind::Uav => binding.uav = register,
+        ResourceKind::Sampler => binding.sampler = register,
+    }
+
+    binding
+}
+
+#[cfg(test)]
+mod resource_binding_map_tests {
+    use super::*;
+
+    #[test]
+    fn resource_binding_for_register_places_register_in_matching_slot() {
+        let resource = Resource {
+            id: 0,
+            name: "cb".to_owned(),
+            desc_set: 1,
+            binding: 2,
+            writable: false,
+            kind: ResourceKind::Cbv,
+        };
+        let register = HlslResourceBindingSpaceRegister {
+            register_space: 1,
+            register_binding: 2,
+        };
+
+        let binding = resource_binding_for_register(spirv::ExecutionModel::Vertex, &resource, register);
+
+        assert_eq!(binding.cbv.register_space, 1);
+        assert_eq!(binding.cbv.register_binding, 2);
+        assert_eq!(binding.uav.register_space, 0);
+        assert_eq!(binding.srv.register_space, 0);
+        assert_eq!(binding.sampler.register_space, 0);
+    }
+}
+
+/// A storage buffer is a SRV (`StructuredBuffer`) unless it's written to or
+/// `force_storage_buffer_as_uav` is set, matching the HLSL backend's own rule.
+fn storage_buffer_kind(writable: bool, force_storage_buffer_as_uav: bool) -> ResourceKind {
+    if force_storage_buffer_as_uav || writable {
+        ResourceKind::Uav
+    } else {
+        ResourceKind::Srv
+    }
+}
+
+/// A storage image is a SRV (`Texture*`) only if it's non-writable and
+/// `nonwritable_uav_texture_as_srv` is set, matching the HLSL backend's own rule.
+fn storage_image_kind(writable: bool, nonwritable_uav_texture_as_srv: bool) -> ResourceKind {
+    if !writable && nonwritable_uav_texture_as_srv {
+        ResourceKind::Srv
+    } else {
+        ResourceKind::Uav
+    }
+}
+
+#[cfg(test)]
+mod resource_kind_tests {
+    use super::*;
+
+    #[test]
+    fn storage_buffer_kind_is_srv_unless_written_or_forced() {
+        assert_eq!(storage_buffer_kind(false, false), ResourceKind::Srv);
+        assert_eq!(storage_buffer_kind(true, false), ResourceKind::Uav);
+        assert_eq!(storage_buffer_kind(false, true), ResourceKind::Uav);
+    }
+
+    #[test]
+    fn storage_image_kind_is_srv_only_when_nonwritable_and_requested() {
+        assert_eq!(storage_image_kind(false, true), ResourceKind::Srv);
+        assert_eq!(storage_image_kind(false, false), ResourceKind::Uav);
+        assert_eq!(storage_image_kind(true, true), ResourceKind::Uav);
+    }
+}
+
+unsafe fn resources_from_raw(
+    ptr: *const br::ScResource,
+    len: usize,
+    kind_for: impl Fn(bool) -> ResourceKind,
+) -> Result<Vec<Resource>, ErrorCode> {
+    if ptr.is_null() || len == 0 {
+        return Ok(Vec::new());
+    }
+
+    std::slice::from_raw_parts(ptr, len)
+        .iter()
+        .map(|raw| {
+            let name = if raw.name.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(raw.name)
+                    .to_str()
+                    .map_err(|_| ErrorCode::Unhandled)?
+                    .to_owned()
+            };
+
+            Ok(Resource {
+                id: raw.id,
+                name,
+                desc_set: raw.desc_set,
+                binding: raw.binding,
+                writable: raw.writable,
+                kind: kind_for(raw.writable),
+            })
+        })
+        .collect()
 }